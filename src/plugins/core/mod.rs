@@ -0,0 +1,50 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use eyre::Result;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::backend::ABackend;
+use crate::cli::args::BackendArg;
+use crate::file;
+
+pub mod zig;
+pub mod zls;
+
+/// Every core backend mise ships in-tree, constructed once at startup.
+pub static CORE_PLUGINS: Lazy<Vec<ABackend>> = Lazy::new(|| {
+    vec![
+        Arc::new(zig::ZigPlugin::new()),
+        Arc::new(zls::ZlsPlugin::new()),
+    ]
+});
+
+/// Build the [`BackendArg`] for a core plugin addressed by its short name.
+pub fn new_backend_arg(short: &str) -> BackendArg {
+    BackendArg::new(short.to_string(), Some(format!("core:{short}")))
+}
+
+/// Extract a `.zip` whose contents are wrapped in a single top-level directory,
+/// lifting that directory up to `dest` so the layout matches a tarball unpacked
+/// with `strip_components: 1` (`unzip` has no such option). `scratch` is a
+/// working directory that is cleared before and after use. A zip without a
+/// single wrapping directory is extracted to `dest` verbatim.
+pub(crate) fn unzip_strip_one(archive: &Path, dest: &Path, scratch: &Path) -> Result<()> {
+    file::remove_all(scratch)?;
+    file::unzip(archive, scratch)?;
+    let mut entries = scratch
+        .read_dir()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .collect_vec();
+    match entries.as_slice() {
+        [only] if only.is_dir() => {
+            let root = entries.pop().unwrap();
+            file::rename(&root, dest)?;
+            file::remove_all(scratch)?;
+        }
+        _ => file::rename(scratch, dest)?,
+    }
+    Ok(())
+}
@@ -0,0 +1,241 @@
+use std::path::{Path, PathBuf};
+
+use crate::backend::Backend;
+use crate::cli::args::BackendArg;
+use crate::cli::version::OS;
+use crate::cmd::CmdLineRunner;
+use crate::config::SETTINGS;
+use crate::file::TarOptions;
+use crate::http::{HTTP, HTTP_FETCH};
+use crate::install_context::InstallContext;
+use crate::toolset::{ToolRequest, ToolVersion};
+use crate::ui::progress_report::SingleReport;
+use crate::{file, hash, plugins};
+use contracts::requires;
+use eyre::Result;
+use itertools::Itertools;
+use versions::Versioning;
+
+#[derive(Debug)]
+pub struct ZigPlugin {
+    ba: BackendArg,
+}
+
+const INDEX_URL: &str = "https://ziglang.org/download/index.json";
+
+impl ZigPlugin {
+    pub fn new() -> Self {
+        Self {
+            ba: plugins::core::new_backend_arg("zig"),
+        }
+    }
+
+    fn bin_path(&self, bin_name: &str, tv: &ToolVersion) -> PathBuf {
+        if cfg!(windows) {
+            tv.install_path().join(format!("{bin_name}.exe"))
+        } else {
+            tv.install_path().join(bin_name)
+        }
+    }
+
+    fn bin_version(&self, bin_name: &str, ctx: &InstallContext, tv: &ToolVersion) -> Result<String> {
+        ctx.pr.set_message((bin_name.to_owned() + " version").into());
+        let output = CmdLineRunner::new(self.bin_path(bin_name, tv))
+            .with_pr(&ctx.pr)
+            .arg("version")
+            .output()?;
+
+        let version = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .unwrap_or("")
+            .trim()
+            .to_string();
+
+        Ok(version)
+    }
+
+    /// Full URL of the download index. Defaults to ziglang.org, but can be
+    /// pointed at a Mach nominated mirror (`https://machengine.org/zig/index.json`)
+    /// or a private mirror via the `zig_mirror` setting (`MISE_ZIG_MIRROR`),
+    /// since nightly artifacts are purged from ziglang.org after ~90 days.
+    fn index_url(&self) -> String {
+        SETTINGS
+            .zig_mirror
+            .clone()
+            .unwrap_or_else(|| INDEX_URL.to_string())
+    }
+
+    fn fetch_index(&self) -> Result<serde_json::Value> {
+        Ok(HTTP_FETCH.json(self.index_url())?)
+    }
+
+    fn download(&self, ctx: &InstallContext, tv: &ToolVersion) -> Result<PathBuf> {
+        let index = self.fetch_index()?;
+        let release = index
+            .get(&tv.version)
+            .ok_or_else(|| eyre::eyre!("No Zig release found for {}", tv.version))?;
+
+        let platform_key = format!("{}-{}", arch(), os());
+        let artifact = release.get(&platform_key).ok_or_else(|| {
+            eyre::eyre!("No Zig build found for {} on {}", tv.version, platform_key)
+        })?;
+        let url = artifact
+            .get("tarball")
+            .and_then(|t| t.as_str())
+            .ok_or_else(|| eyre::eyre!("Zig release {} is missing a tarball URL", tv.version))?;
+        let expected_shasum = artifact
+            .get("shasum")
+            .and_then(|s| s.as_str())
+            .ok_or_else(|| eyre::eyre!("Zig release {} is missing a shasum", tv.version))?;
+
+        let filename = url.split('/').last().unwrap();
+        let tarball_path = tv.download_path().join(filename);
+
+        ctx.pr.set_message(format!("download {filename}"));
+        self.download_from_mirrors(url, &tarball_path, expected_shasum, &*ctx.pr)?;
+
+        Ok(tarball_path)
+    }
+
+    /// Download `url` into `dest`, trying each configured mirror base in
+    /// `zig_mirrors` first and falling back to the canonical URL last. The
+    /// advertised sha256 is verified per candidate so a mirror that serves a
+    /// truncated-but-200 body is skipped rather than aborting the install;
+    /// selection only succeeds on a candidate whose hash matches.
+    fn download_from_mirrors(
+        &self,
+        url: &str,
+        dest: &Path,
+        shasum: &str,
+        pr: &dyn SingleReport,
+    ) -> Result<()> {
+        // Each candidate replaces the canonical URL's scheme and host with a
+        // mirror base, keeping the rest of the path; the canonical URL is
+        // always tried last.
+        let path = url.splitn(4, '/').nth(3).unwrap_or(url);
+        let mut candidates: Vec<String> = SETTINGS
+            .zig_mirrors
+            .iter()
+            .map(|base| format!("{}/{}", base.trim_end_matches('/'), path))
+            .collect();
+        candidates.push(url.to_string());
+
+        let mut last_err = None;
+        for candidate in candidates {
+            if let Err(e) = HTTP.download_file(&candidate, dest, Some(pr)) {
+                last_err = Some(e);
+                continue;
+            }
+            let actual = hash::file_hash_sha256(dest, None)?;
+            if actual == shasum {
+                return Ok(());
+            }
+            last_err = Some(eyre::eyre!(
+                "sha256 mismatch from {candidate}: expected {shasum}, got {actual}"
+            ));
+        }
+        Err(last_err.unwrap_or_else(|| eyre::eyre!("no download URL available for Zig")))
+    }
+
+    fn install(&self, ctx: &InstallContext, tv: &ToolVersion, tarball_path: &Path) -> Result<()> {
+        let filename = tarball_path.file_name().unwrap().to_string_lossy();
+        ctx.pr.set_message(format!("extract {filename}"));
+        file::remove_all(tv.install_path())?;
+
+        // ziglang.org ships Windows builds as `.zip` and every other target as a
+        // `.tar.xz`; pick the extractor from the artifact extension.
+        if filename.ends_with(".zip") {
+            plugins::core::unzip_strip_one(
+                tarball_path,
+                &tv.install_path(),
+                &tv.download_path().join("extract"),
+            )?;
+        } else {
+            file::untar(
+                tarball_path,
+                &tv.install_path(),
+                &TarOptions {
+                    strip_components: 1,
+                    pr: Some(&ctx.pr),
+                    ..Default::default()
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    fn verify(&self, ctx: &InstallContext, tv: &ToolVersion) -> Result<()> {
+        let version = self.bin_version("zig", ctx, tv)?;
+        ctx.pr.set_message(format!("verified zig {}", version));
+        Ok(())
+    }
+}
+
+impl Backend for ZigPlugin {
+    fn ba(&self) -> &BackendArg {
+        &self.ba
+    }
+
+    fn list_remote_versions(&self) -> Result<Vec<String>> {
+        let index = self.fetch_index()?;
+        let versions = index
+            .as_object()
+            .map(|o| o.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            // `master` is the mutable nightly pointer; it is installable by name
+            // but is not a concrete version in the listing.
+            .filter(|v| v != "master")
+            .unique()
+            .sorted_by_cached_key(|s| (Versioning::new(s), s.to_string()))
+            .collect();
+
+        Ok(versions)
+    }
+
+    fn list_bin_paths(&self, tv: &ToolVersion) -> Result<Vec<PathBuf>> {
+        Ok(vec![tv.install_path()])
+    }
+
+    fn idiomatic_install_path(&self, _tv: &ToolVersion) -> Result<()> {
+        Ok(())
+    }
+
+    #[requires(matches!(tv.request, ToolRequest::Version { .. } | ToolRequest::Prefix { .. } | ToolRequest::Ref { .. }), "unsupported tool version request type")]
+    fn install_version_(&self, ctx: &InstallContext, tv: ToolVersion) -> Result<ToolVersion> {
+        let tarball_path = self.download(ctx, &tv)?;
+        self.install(ctx, &tv, &tarball_path)?;
+        self.verify(ctx, &tv)?;
+        Ok(tv)
+    }
+}
+
+fn os() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "macos"
+    } else if cfg!(target_os = "linux") {
+        "linux"
+    } else if cfg!(target_os = "freebsd") {
+        "freebsd"
+    } else if cfg!(target_os = "windows") {
+        "windows"
+    } else {
+        &OS
+    }
+}
+
+fn arch() -> &'static str {
+    let arch = SETTINGS.arch();
+    if arch == "x86_64" {
+        "x86_64"
+    } else if arch == "aarch64" {
+        "aarch64"
+    } else if arch == "arm" {
+        "armv7a"
+    } else if arch == "riscv64" {
+        "riscv64"
+    } else {
+        arch
+    }
+}
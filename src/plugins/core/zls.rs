@@ -10,9 +10,10 @@ use crate::http::{HTTP, HTTP_FETCH};
 use crate::install_context::InstallContext;
 use crate::toolset::{ToolRequest, ToolVersion};
 use crate::ui::progress_report::SingleReport;
-use crate::{file, github, minisign, plugins};
+use crate::{file, github, hash, minisign, plugins};
 use contracts::requires;
 use eyre::Result;
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 use itertools::Itertools;
 use versions::Versioning;
 use xx::regex;
@@ -24,6 +25,19 @@ pub struct ZlsPlugin {
 
 const MINISIGN_KEY: &str = "RWR+9B91GBZ0zOjh6Lr17+zKf5BoSuFvrx2xSeDE57uIYvnKBGmMjOex";
 
+/// Characters escaped in the `zig_version` query value. Only what is
+/// significant in a query string: `+` carries build metadata in Zig dev
+/// versions (`0.13.0-dev.7+73c6c13a`) and must not be read as a space, plus
+/// the standard reserved delimiters. Ordinary tags like `0.13.0` pass through
+/// unescaped so the URL stays readable.
+const QUERY_VALUE: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'+')
+    .add(b'&')
+    .add(b'=')
+    .add(b'#')
+    .add(b'%');
+
 impl ZlsPlugin {
     pub fn new() -> Self {
         Self {
@@ -71,36 +85,93 @@ impl ZlsPlugin {
             tv.version.clone()
         };
 
-        let url = self.fetch_url_from_zigtools(&zls_version)?;
+        let artifact = self.fetch_url_from_zigtools(&zls_version, &self.compatibility(tv))?;
+        let url = artifact.url;
 
         let filename = url.split('/').last().unwrap();
         let tarball_path = tv.download_path().join(filename);
 
         ctx.pr.set_message(format!("download {filename}"));
-        HTTP.download_file(&url, &tarball_path, Some(&ctx.pr))?;
+        let source = self.download_from_mirrors(&url, &tarball_path, &artifact.shasum, &*ctx.pr)?;
 
+        // Minisign verification is against MINISIGN_KEY regardless of which
+        // mirror served the tarball, so integrity holds for every source.
         ctx.pr.set_message(format!("minisign {filename}"));
         let tarball_data = file::read(&tarball_path)?;
-        let sig = HTTP.get_text(format!("{url}.minisig"))?;
+        let sig = HTTP.get_text(format!("{source}.minisig"))?;
         minisign::verify(MINISIGN_KEY, &tarball_data, &sig)?;
 
         Ok(tarball_path)
     }
 
+    /// Download `url` into `dest`, trying each configured mirror base in
+    /// `zls_mirrors` first and falling back to the canonical URL last. The
+    /// advertised sha256 is verified per candidate so a mirror that serves a
+    /// truncated-but-200 body is skipped rather than aborting the install;
+    /// selection only succeeds on a candidate whose hash matches. Returns the
+    /// URL that actually succeeded so the caller can fetch its signature.
+    fn download_from_mirrors(
+        &self,
+        url: &str,
+        dest: &Path,
+        shasum: &str,
+        pr: &dyn SingleReport,
+    ) -> Result<String> {
+        // Each candidate replaces the canonical URL's scheme and host with a
+        // mirror base, keeping the rest of the path; the canonical URL is
+        // always tried last.
+        let path = url.splitn(4, '/').nth(3).unwrap_or(url);
+        let mut candidates: Vec<String> = SETTINGS
+            .zls_mirrors
+            .iter()
+            .map(|base| format!("{}/{}", base.trim_end_matches('/'), path))
+            .collect();
+        candidates.push(url.to_string());
+
+        let mut last_err = None;
+        for candidate in candidates {
+            if let Err(e) = HTTP.download_file(&candidate, dest, Some(pr)) {
+                last_err = Some(e);
+                continue;
+            }
+            let actual = hash::file_hash_sha256(dest, None)?;
+            if actual == shasum {
+                return Ok(candidate);
+            }
+            last_err = Some(eyre::eyre!(
+                "sha256 mismatch from {candidate}: expected {shasum}, got {actual}"
+            ));
+        }
+        Err(last_err.unwrap_or_else(|| eyre::eyre!("no download URL available for ZLS")))
+    }
+
     fn install(&self, ctx: &InstallContext, tv: &ToolVersion, tarball_path: &Path) -> Result<()> {
         let filename = tarball_path.file_name().unwrap().to_string_lossy();
         ctx.pr.set_message(format!("extract {filename}"));
         file::remove_all(tv.install_path())?;
-        file::untar(
-            tarball_path,
-            &tv.install_path(),
-            &TarOptions {
-                strip_components: 1,
-                pr: Some(&ctx.pr),
-                ..Default::default()
-            },
-        )?;
 
+        // zigtools ships Windows builds as `.zip` and every other target as a
+        // `.tar.xz`/`.tar.gz`; pick the extractor from the artifact extension.
+        if filename.ends_with(".zip") {
+            plugins::core::unzip_strip_one(
+                tarball_path,
+                &tv.install_path(),
+                &tv.download_path().join("extract"),
+            )?;
+        } else {
+            file::untar(
+                tarball_path,
+                &tv.install_path(),
+                &TarOptions {
+                    strip_components: 1,
+                    pr: Some(&ctx.pr),
+                    ..Default::default()
+                },
+            )?;
+        }
+
+        // On Windows the zip lays `zls.exe` down at the install root, which
+        // `bin_path` already points at, so the `bin/zls` shim is Unix-only.
         if cfg!(unix) {
             file::create_dir_all(tv.install_path().join("bin"))?;
             file::make_symlink(Path::new("../zls"), &tv.install_path().join("bin/zls"))?;
@@ -115,38 +186,72 @@ impl ZlsPlugin {
         Ok(())
     }
 
-    fn fetch_url_from_zigtools(&self, zls_version: &str) -> Result<String> {
-        let json_url = format!("https://releases.zigtools.org/v1/zls/select-version?zig_version={}&compatibility=only-runtime", zls_version);
+    /// Compatibility mode passed to the zigtools worker. `only-runtime` (the
+    /// default) returns a ZLS that runs against the given Zig; `full` returns
+    /// one that can also be compiled from source with it. Controlled by the
+    /// `compatibility` tool option (`zls = { version = "...", compatibility =
+    /// "full" }`), defaulting to `only-runtime`.
+    fn compatibility(&self, tv: &ToolVersion) -> String {
+        tv.request
+            .options()
+            .get("compatibility")
+            .cloned()
+            .unwrap_or_else(|| "only-runtime".to_string())
+    }
+
+    fn fetch_url_from_zigtools(
+        &self,
+        zls_version: &str,
+        compatibility: &str,
+    ) -> Result<ZigtoolsArtifact> {
+        // Zig dev builds carry build metadata (e.g. `0.13.0-dev.7+73c6c13a`);
+        // the `+` must be percent-encoded so the worker sees the version
+        // verbatim rather than reading the `+` as a space.
+        let zig_version = utf8_percent_encode(zls_version, QUERY_VALUE);
+        let json_url = format!("https://releases.zigtools.org/v1/zls/select-version?zig_version={}&compatibility={}", zig_version, compatibility);
 
         let version_json: serde_json::Value = HTTP_FETCH.json(json_url)?;
-        
+
         // Check if there's an error code in the response
         if let Some(code) = version_json.get("code") {
             let message = version_json["message"].as_str().unwrap_or("Unknown error");
             return Err(eyre::eyre!("ZLS API error (code {}): {}", code, message));
         }
-        
+
         // Get the appropriate tarball URL based on OS and architecture
         let os_key = format!("{}-{}", os(), arch());
-        
+
         if let Some(platform) = version_json.get(&os_key) {
-            if let Some(tarball) = platform.get("tarball") {
-                if let Some(url) = tarball.as_str() {
-                    return Ok(url.to_string());
-                }
+            if let (Some(url), Some(shasum)) = (
+                platform.get("tarball").and_then(|t| t.as_str()),
+                platform.get("shasum").and_then(|s| s.as_str()),
+            ) {
+                return Ok(ZigtoolsArtifact {
+                    url: url.to_string(),
+                    shasum: shasum.to_string(),
+                });
             }
         }
-        
+
         Err(eyre::eyre!("No compatible ZLS build found for {} on {}", zls_version, os_key))
     }
 }
 
+/// A single platform entry from the zigtools `select-version` response.
+struct ZigtoolsArtifact {
+    url: String,
+    shasum: String,
+}
+
 impl Backend for ZlsPlugin {
     fn ba(&self) -> &BackendArg {
         &self.ba
     }
 
     fn list_remote_versions(&self) -> Result<Vec<String>> {
+        // `Versioning` already orders pre-release identifiers (e.g. Zig/ZLS
+        // `-dev.N+<hash>` nightlies) below their stable tag, so dev builds sort
+        // correctly relative to stable releases without special handling here.
         let mut versions: Vec<String> = github::list_releases("zigtools/zls")?
             .into_iter()
             .map(|r| r.tag_name)